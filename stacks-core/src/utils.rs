@@ -1,15 +1,50 @@
 use std::{
     borrow::Borrow,
+    collections::BTreeMap,
     fmt::{Display, Formatter},
+    io::{self, Cursor, Read, Write},
     ops::Deref,
+    str::FromStr,
 };
 
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_bytes::ByteBuf;
 use thiserror::Error;
 
 use crate::address::{AddressVersion, StacksAddress};
 
+/// Type-id byte identifying a standard principal in the consensus wire format
+const PRINCIPAL_TYPE_ID_STANDARD: u8 = 0x05;
+/// Type-id byte identifying a contract principal in the consensus wire format
+const PRINCIPAL_TYPE_ID_CONTRACT: u8 = 0x06;
+/// Type-id byte identifying a versioned contract principal in the consensus wire format
+///
+/// Chosen outside the 0x00-0x0e range used by `ClarityValue`'s own type-ids, since a
+/// `PrincipalData` value's type-id byte is embedded directly as a `ClarityValue::Principal`.
+const PRINCIPAL_TYPE_ID_VERSIONED_CONTRACT: u8 = 0x0f;
+/// Marker byte indicating a Clarity version follows in the versioned contract payload
+const CLARITY_VERSION_PRESENT: u8 = 0x01;
+
+/// Type-id bytes identifying each `ClarityValue` variant in the consensus wire format
+const CLARITY_TYPE_ID_INT: u8 = 0x00;
+const CLARITY_TYPE_ID_UINT: u8 = 0x01;
+const CLARITY_TYPE_ID_BUFFER: u8 = 0x02;
+const CLARITY_TYPE_ID_BOOL_TRUE: u8 = 0x03;
+const CLARITY_TYPE_ID_BOOL_FALSE: u8 = 0x04;
+const CLARITY_TYPE_ID_RESPONSE_OK: u8 = 0x07;
+const CLARITY_TYPE_ID_RESPONSE_ERR: u8 = 0x08;
+const CLARITY_TYPE_ID_OPTIONAL_NONE: u8 = 0x09;
+const CLARITY_TYPE_ID_OPTIONAL_SOME: u8 = 0x0a;
+const CLARITY_TYPE_ID_LIST: u8 = 0x0b;
+const CLARITY_TYPE_ID_TUPLE: u8 = 0x0c;
+const CLARITY_TYPE_ID_STRING_ASCII: u8 = 0x0d;
+const CLARITY_TYPE_ID_STRING_UTF8: u8 = 0x0e;
+
+/// Maximum serialized size, in bytes, of a Clarity buffer or string value
+pub const CLARITY_MAX_VALUE_SIZE: u32 = 1_048_576;
+
 /// Minimum length of a contract name
 pub const CONTRACT_MIN_NAME_LENGTH: usize = 1;
 /// Maximum length of a contract name
@@ -45,10 +80,51 @@ pub enum ContractNameError {
     InvalidFormat,
 }
 
+/// Minimum length of a Clarity name
+pub const CLARITY_MIN_NAME_LENGTH: usize = 1;
+/// Maximum length of a Clarity name
+pub const CLARITY_MAX_NAME_LENGTH: usize = 128;
+
+/// Regex string for Clarity name validation
+pub static CLARITY_NAME_REGEX_STRING: Lazy<String> = Lazy::new(|| {
+    format!(
+        r#"([a-zA-Z_$!?+<>=/*-](([a-zA-Z0-9_$!?+<>=/*-])){{{},{}}})"#,
+        CLARITY_MIN_NAME_LENGTH - 1,
+        CLARITY_MAX_NAME_LENGTH - 1
+    )
+});
+
+/// Regex for Clarity name validation
+pub static CLARITY_NAME_REGEX: Lazy<Regex> = Lazy::new(|| {
+    regex::Regex::new(format!("^{}$", CLARITY_NAME_REGEX_STRING.as_str()).as_str()).unwrap()
+});
+
+#[derive(Error, Debug)]
+/// Error type for Clarity name validation
+pub enum ClarityNameError {
+    #[error(
+        "Length should be between {} and {}",
+        CLARITY_MIN_NAME_LENGTH,
+        CLARITY_MAX_NAME_LENGTH
+    )]
+    /// Invalid Clarity name length
+    InvalidLength,
+    #[error("Format should follow the Clarity name specification")]
+    /// Invalid Clarity name format
+    InvalidFormat,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 /// Contract name type
 pub struct ContractName(String);
 
 impl ContractName {
+    /// The exact number of bytes `consensus_serialize` would produce for this value,
+    /// without materializing the serialized buffer
+    pub fn serialized_len(&self) -> usize {
+        1 + self.0.len()
+    }
+
     /// Create a new contract name from the provided string
     pub fn new(contract_name: &str) -> Result<Self, ContractNameError> {
         if contract_name.len() < CONTRACT_MIN_NAME_LENGTH
@@ -104,7 +180,7 @@ impl Display for ContractName {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 /// Standard principal data type
 pub struct StandardPrincipalData(AddressVersion, StacksAddress);
 
@@ -113,11 +189,1142 @@ impl StandardPrincipalData {
     pub fn new(version: AddressVersion, address: StacksAddress) -> Self {
         Self(version, address)
     }
+
+    /// The exact number of bytes `consensus_serialize` would produce for this value,
+    /// without materializing the serialized buffer
+    pub fn serialized_len(&self) -> usize {
+        // 1 version byte + 20 hash bytes
+        21
+    }
 }
+#[derive(Debug, Clone, PartialEq, Eq)]
 /// Principal Data type
 pub enum PrincipalData {
     /// Standard principal data type
     Standard(StandardPrincipalData),
     /// Contract principal data type
     Contract(StandardPrincipalData, ContractName),
+    /// Versioned contract principal data type, tagging the deployed contract with the
+    /// Clarity version it was published under
+    VersionedContract(StandardPrincipalData, ContractName, ClarityVersion),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The Clarity language version a smart contract was deployed under
+pub enum ClarityVersion {
+    /// Clarity 1
+    Clarity1,
+    /// Clarity 2
+    Clarity2,
+    /// Clarity 3
+    Clarity3,
+}
+
+impl From<ClarityVersion> for u8 {
+    fn from(version: ClarityVersion) -> Self {
+        match version {
+            ClarityVersion::Clarity1 => 1,
+            ClarityVersion::Clarity2 => 2,
+            ClarityVersion::Clarity3 => 3,
+        }
+    }
+}
+
+impl TryFrom<u8> for ClarityVersion {
+    type Error = CodecError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(ClarityVersion::Clarity1),
+            2 => Ok(ClarityVersion::Clarity2),
+            3 => Ok(ClarityVersion::Clarity3),
+            other => Err(CodecError::InvalidClarityVersion(other)),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+/// Error type for consensus (de)serialization of the types in this module
+pub enum CodecError {
+    #[error("IO error during (de)serialization: {0}")]
+    /// An IO error occurred while reading from or writing to the underlying stream
+    IoError(#[from] io::Error),
+    #[error("Invalid principal type id {0}")]
+    /// The type-id byte did not match a known `PrincipalData` variant
+    InvalidTypeId(u8),
+    #[error("Invalid address version {0}")]
+    /// The version byte did not match a known `AddressVersion`
+    InvalidAddressVersion(u8),
+    #[error("Invalid contract name: {0}")]
+    /// The decoded contract name failed validation
+    InvalidContractName(#[from] ContractNameError),
+    #[error("Invalid Clarity value type id {0}")]
+    /// The type-id byte did not match a known `ClarityValue` variant
+    InvalidClarityTypeId(u8),
+    #[error("Clarity value length {len} exceeds the {max} maximum")]
+    /// A buffer or string byte length, or a decoded list's element count, exceeded
+    /// `CLARITY_MAX_VALUE_SIZE`; tuple entry counts are not checked
+    ValueTooLarge {
+        /// The length that was encountered
+        len: usize,
+        /// The maximum permitted length
+        max: usize,
+    },
+    #[error("Invalid ASCII string in Clarity value")]
+    /// A `StringAscii` value contained non-ASCII bytes or invalid UTF-8
+    InvalidAsciiString,
+    #[error("Invalid UTF-8 string in Clarity value")]
+    /// A `StringUtf8` value contained invalid UTF-8
+    InvalidUtf8String,
+    #[error("Invalid Clarity name: {0}")]
+    /// The decoded Clarity name failed validation
+    InvalidClarityName(#[from] ClarityNameError),
+    #[error("Invalid Clarity version {0}")]
+    /// The version byte did not match a known `ClarityVersion`
+    InvalidClarityVersion(u8),
+    #[error("Invalid Clarity version marker byte {0}")]
+    /// The marker byte preceding a versioned contract's Clarity version was not `0x01`
+    InvalidClarityVersionMarker(u8),
+}
+
+/// Trait for the Stacks consensus-critical binary wire format, mirroring how
+/// blocks and transactions are (de)serialized for hashing and network transfer
+pub trait StacksCodec: Sized {
+    /// Serialize `self` to `w` using the consensus wire format
+    fn consensus_serialize<W: Write>(&self, w: &mut W) -> Result<(), CodecError>;
+
+    /// Deserialize `Self` from `r`, which is encoded in the consensus wire format
+    fn consensus_deserialize<R: Read>(r: &mut R) -> Result<Self, CodecError>;
+}
+
+impl StacksCodec for ContractName {
+    fn consensus_serialize<W: Write>(&self, w: &mut W) -> Result<(), CodecError> {
+        let bytes = self.0.as_bytes();
+        w.write_all(&[bytes.len() as u8])?;
+        w.write_all(bytes)?;
+
+        Ok(())
+    }
+
+    fn consensus_deserialize<R: Read>(r: &mut R) -> Result<Self, CodecError> {
+        let mut len_buf = [0u8; 1];
+        r.read_exact(&mut len_buf)?;
+
+        let mut name_buf = vec![0u8; len_buf[0] as usize];
+        r.read_exact(&mut name_buf)?;
+
+        let name = String::from_utf8(name_buf)
+            .map_err(|_| CodecError::InvalidContractName(ContractNameError::InvalidFormat))?;
+
+        Ok(ContractName::new(&name)?)
+    }
+}
+
+impl StacksCodec for StandardPrincipalData {
+    fn consensus_serialize<W: Write>(&self, w: &mut W) -> Result<(), CodecError> {
+        w.write_all(&[u8::from(self.0)])?;
+        w.write_all(&self.1.to_bytes())?;
+
+        Ok(())
+    }
+
+    fn consensus_deserialize<R: Read>(r: &mut R) -> Result<Self, CodecError> {
+        let mut version_buf = [0u8; 1];
+        r.read_exact(&mut version_buf)?;
+        let version = AddressVersion::try_from(version_buf[0])
+            .map_err(|_| CodecError::InvalidAddressVersion(version_buf[0]))?;
+
+        let mut hash_buf = [0u8; 20];
+        r.read_exact(&mut hash_buf)?;
+        let address = StacksAddress::from(hash_buf);
+
+        Ok(StandardPrincipalData::new(version, address))
+    }
+}
+
+impl PrincipalData {
+    /// The exact number of bytes `consensus_serialize` would produce for this value,
+    /// without materializing the serialized buffer
+    pub fn serialized_len(&self) -> usize {
+        match self {
+            // 1 type-id byte
+            PrincipalData::Standard(principal) => 1 + principal.serialized_len(),
+            // 1 type-id byte
+            PrincipalData::Contract(principal, contract_name) => {
+                1 + principal.serialized_len() + contract_name.serialized_len()
+            }
+            // 1 type-id byte + 1 marker byte + 1 version byte
+            PrincipalData::VersionedContract(principal, contract_name, _) => {
+                3 + principal.serialized_len() + contract_name.serialized_len()
+            }
+        }
+    }
+}
+
+impl StacksCodec for PrincipalData {
+    fn consensus_serialize<W: Write>(&self, w: &mut W) -> Result<(), CodecError> {
+        match self {
+            PrincipalData::Standard(principal) => {
+                w.write_all(&[PRINCIPAL_TYPE_ID_STANDARD])?;
+                principal.consensus_serialize(w)?;
+            }
+            PrincipalData::Contract(principal, contract_name) => {
+                w.write_all(&[PRINCIPAL_TYPE_ID_CONTRACT])?;
+                principal.consensus_serialize(w)?;
+                contract_name.consensus_serialize(w)?;
+            }
+            PrincipalData::VersionedContract(principal, contract_name, version) => {
+                w.write_all(&[PRINCIPAL_TYPE_ID_VERSIONED_CONTRACT, CLARITY_VERSION_PRESENT])?;
+                w.write_all(&[u8::from(*version)])?;
+                principal.consensus_serialize(w)?;
+                contract_name.consensus_serialize(w)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn consensus_deserialize<R: Read>(r: &mut R) -> Result<Self, CodecError> {
+        let mut type_id_buf = [0u8; 1];
+        r.read_exact(&mut type_id_buf)?;
+
+        Self::consensus_deserialize_with_type_id(type_id_buf[0], r)
+    }
+}
+
+impl PrincipalData {
+    /// Deserialize the principal payload that follows an already-read type-id byte
+    ///
+    /// Shared with [`ClarityValue::consensus_deserialize`], whose `Principal` variant
+    /// embeds a `PrincipalData` directly (type-id byte included) rather than behind its
+    /// own wrapper, so the two decoders must stay in sync on every principal type-id.
+    fn consensus_deserialize_with_type_id<R: Read>(
+        type_id: u8,
+        r: &mut R,
+    ) -> Result<Self, CodecError> {
+        match type_id {
+            PRINCIPAL_TYPE_ID_STANDARD => Ok(PrincipalData::Standard(
+                StandardPrincipalData::consensus_deserialize(r)?,
+            )),
+            PRINCIPAL_TYPE_ID_CONTRACT => {
+                let principal = StandardPrincipalData::consensus_deserialize(r)?;
+                let contract_name = ContractName::consensus_deserialize(r)?;
+
+                Ok(PrincipalData::Contract(principal, contract_name))
+            }
+            PRINCIPAL_TYPE_ID_VERSIONED_CONTRACT => {
+                let mut marker_buf = [0u8; 1];
+                r.read_exact(&mut marker_buf)?;
+                if marker_buf[0] != CLARITY_VERSION_PRESENT {
+                    return Err(CodecError::InvalidClarityVersionMarker(marker_buf[0]));
+                }
+
+                let mut version_buf = [0u8; 1];
+                r.read_exact(&mut version_buf)?;
+                let version = ClarityVersion::try_from(version_buf[0])?;
+
+                let principal = StandardPrincipalData::consensus_deserialize(r)?;
+                let contract_name = ContractName::consensus_deserialize(r)?;
+
+                Ok(PrincipalData::VersionedContract(
+                    principal,
+                    contract_name,
+                    version,
+                ))
+            }
+            other => Err(CodecError::InvalidTypeId(other)),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+/// Error type for parsing a [`PrincipalData`] or [`StandardPrincipalData`] from a string
+pub enum PrincipalDataError {
+    #[error("Invalid c32 checksum")]
+    /// The address portion failed c32check decoding
+    InvalidChecksum,
+    #[error("Invalid address version byte {0}")]
+    /// The decoded version byte did not match a known `AddressVersion`
+    InvalidVersion(u8),
+    #[error("Invalid contract name: {0}")]
+    /// The contract name portion failed validation
+    InvalidContractName(#[from] ContractNameError),
+    #[error("Invalid Clarity version suffix {0:?}")]
+    /// The number after a `+v` Clarity version suffix was empty, non-numeric, or out of range
+    InvalidClarityVersion(String),
+}
+
+impl FromStr for StandardPrincipalData {
+    type Err = PrincipalDataError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hash_bytes, version_byte) =
+            c32::decode_check(s).map_err(|_| PrincipalDataError::InvalidChecksum)?;
+
+        let version = AddressVersion::try_from(version_byte)
+            .map_err(|_| PrincipalDataError::InvalidVersion(version_byte))?;
+
+        let hash_bytes: [u8; 20] = hash_bytes
+            .try_into()
+            .map_err(|_| PrincipalDataError::InvalidChecksum)?;
+
+        Ok(StandardPrincipalData::new(version, StacksAddress::from(hash_bytes)))
+    }
+}
+
+impl Display for StandardPrincipalData {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        c32::encode_check(&self.1.to_bytes(), u8::from(self.0)).fmt(f)
+    }
+}
+
+impl FromStr for PrincipalData {
+    type Err = PrincipalDataError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('.') {
+            Some((address, contract_name)) => {
+                let principal = address.parse()?;
+
+                match contract_name.split_once("+v") {
+                    Some((contract_name, version_str)) => {
+                        let version_num: u8 = version_str.parse().map_err(|_| {
+                            PrincipalDataError::InvalidClarityVersion(version_str.to_string())
+                        })?;
+                        let version = ClarityVersion::try_from(version_num).map_err(|_| {
+                            PrincipalDataError::InvalidClarityVersion(version_str.to_string())
+                        })?;
+
+                        Ok(PrincipalData::VersionedContract(
+                            principal,
+                            ContractName::try_from(contract_name)?,
+                            version,
+                        ))
+                    }
+                    None => Ok(PrincipalData::Contract(
+                        principal,
+                        ContractName::try_from(contract_name)?,
+                    )),
+                }
+            }
+            None => Ok(PrincipalData::Standard(s.parse()?)),
+        }
+    }
+}
+
+impl Display for PrincipalData {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            PrincipalData::Standard(principal) => principal.fmt(f),
+            PrincipalData::Contract(principal, contract_name) => {
+                write!(f, "{}.{}", principal, contract_name)
+            }
+            // Delegates the address portion to `StandardPrincipalData`'s `Display`, so it
+            // round-trips using the c32 crate's real `encode_check`/`decode_check` API.
+            PrincipalData::VersionedContract(principal, contract_name, version) => {
+                write!(f, "{}.{}+v{}", principal, contract_name, u8::from(*version))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+/// Name type for Clarity tuple keys, function names, and variable names
+pub struct ClarityName(String);
+
+impl ClarityName {
+    /// The exact number of bytes `consensus_serialize` would produce for this value,
+    /// without materializing the serialized buffer
+    pub fn serialized_len(&self) -> usize {
+        1 + self.0.len()
+    }
+
+    /// Create a new Clarity name from the provided string
+    pub fn new(clarity_name: &str) -> Result<Self, ClarityNameError> {
+        if clarity_name.len() < CLARITY_MIN_NAME_LENGTH
+            || clarity_name.len() > CLARITY_MAX_NAME_LENGTH
+        {
+            Err(ClarityNameError::InvalidLength)
+        } else if CLARITY_NAME_REGEX.is_match(clarity_name) {
+            Ok(Self(clarity_name.to_string()))
+        } else {
+            Err(ClarityNameError::InvalidFormat)
+        }
+    }
+}
+
+impl TryFrom<&str> for ClarityName {
+    type Error = ClarityNameError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        ClarityName::new(value)
+    }
+}
+
+impl AsRef<str> for ClarityName {
+    fn as_ref(&self) -> &str {
+        self.0.as_ref()
+    }
+}
+
+impl Deref for ClarityName {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Borrow<str> for ClarityName {
+    fn borrow(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+impl Display for ClarityName {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl StacksCodec for ClarityName {
+    fn consensus_serialize<W: Write>(&self, w: &mut W) -> Result<(), CodecError> {
+        let bytes = self.0.as_bytes();
+        w.write_all(&[bytes.len() as u8])?;
+        w.write_all(bytes)?;
+
+        Ok(())
+    }
+
+    fn consensus_deserialize<R: Read>(r: &mut R) -> Result<Self, CodecError> {
+        let mut len_buf = [0u8; 1];
+        r.read_exact(&mut len_buf)?;
+
+        let mut name_buf = vec![0u8; len_buf[0] as usize];
+        r.read_exact(&mut name_buf)?;
+
+        let name = String::from_utf8(name_buf)
+            .map_err(|_| CodecError::InvalidClarityName(ClarityNameError::InvalidFormat))?;
+
+        Ok(ClarityName::new(&name)?)
+    }
+}
+
+/// Clarity's general-purpose value type, covering the full Clarity type system
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClarityValue {
+    /// A signed 128-bit integer
+    Int(i128),
+    /// An unsigned 128-bit integer
+    UInt(u128),
+    /// A boolean
+    Bool(bool),
+    /// A buffer of raw bytes
+    Buffer(Vec<u8>),
+    /// An ASCII string
+    StringAscii(String),
+    /// A UTF-8 string
+    StringUtf8(String),
+    /// A principal, standard or contract
+    Principal(PrincipalData),
+    /// A tuple mapping names to values
+    Tuple(BTreeMap<ClarityName, ClarityValue>),
+    /// A list of values, all of the same Clarity type
+    List(Vec<ClarityValue>),
+    /// An optional value
+    Optional(Option<Box<ClarityValue>>),
+    /// The result of a contract call, either committed (ok) or rolled back (err)
+    Response {
+        /// Whether the underlying transaction should be committed
+        committed: bool,
+        /// The wrapped response value
+        data: Box<ClarityValue>,
+    },
+}
+
+impl ClarityValue {
+    /// The exact number of bytes `consensus_serialize` would produce for this value,
+    /// without materializing the serialized buffer
+    pub fn serialized_len(&self) -> usize {
+        match self {
+            // 1 type-id byte + 16 bytes
+            ClarityValue::Int(_) | ClarityValue::UInt(_) => 17,
+            // 1 type-id byte
+            ClarityValue::Bool(_) => 1,
+            // 1 type-id byte + 4 length bytes
+            ClarityValue::Buffer(bytes) => 5 + bytes.len(),
+            ClarityValue::StringAscii(string) => 5 + string.len(),
+            ClarityValue::StringUtf8(string) => 5 + string.len(),
+            ClarityValue::Principal(principal) => principal.serialized_len(),
+            // 1 type-id byte + 4 count bytes
+            ClarityValue::Tuple(entries) => {
+                5 + entries
+                    .iter()
+                    .map(|(name, value)| name.serialized_len() + value.serialized_len())
+                    .sum::<usize>()
+            }
+            // 1 type-id byte + 4 count bytes
+            ClarityValue::List(values) => {
+                5 + values.iter().map(ClarityValue::serialized_len).sum::<usize>()
+            }
+            ClarityValue::Optional(None) => 1,
+            ClarityValue::Optional(Some(value)) => 1 + value.serialized_len(),
+            ClarityValue::Response { data, .. } => 1 + data.serialized_len(),
+        }
+    }
+}
+
+impl StacksCodec for ClarityValue {
+    fn consensus_serialize<W: Write>(&self, w: &mut W) -> Result<(), CodecError> {
+        match self {
+            ClarityValue::Int(value) => {
+                w.write_all(&[CLARITY_TYPE_ID_INT])?;
+                w.write_all(&value.to_be_bytes())?;
+            }
+            ClarityValue::UInt(value) => {
+                w.write_all(&[CLARITY_TYPE_ID_UINT])?;
+                w.write_all(&value.to_be_bytes())?;
+            }
+            ClarityValue::Bool(true) => w.write_all(&[CLARITY_TYPE_ID_BOOL_TRUE])?,
+            ClarityValue::Bool(false) => w.write_all(&[CLARITY_TYPE_ID_BOOL_FALSE])?,
+            ClarityValue::Buffer(bytes) => {
+                if bytes.len() as u32 > CLARITY_MAX_VALUE_SIZE {
+                    return Err(CodecError::ValueTooLarge {
+                        len: bytes.len(),
+                        max: CLARITY_MAX_VALUE_SIZE as usize,
+                    });
+                }
+
+                w.write_all(&[CLARITY_TYPE_ID_BUFFER])?;
+                w.write_all(&(bytes.len() as u32).to_be_bytes())?;
+                w.write_all(bytes)?;
+            }
+            ClarityValue::StringAscii(string) => {
+                if !string.is_ascii() {
+                    return Err(CodecError::InvalidAsciiString);
+                }
+
+                let bytes = string.as_bytes();
+                if bytes.len() as u32 > CLARITY_MAX_VALUE_SIZE {
+                    return Err(CodecError::ValueTooLarge {
+                        len: bytes.len(),
+                        max: CLARITY_MAX_VALUE_SIZE as usize,
+                    });
+                }
+
+                w.write_all(&[CLARITY_TYPE_ID_STRING_ASCII])?;
+                w.write_all(&(bytes.len() as u32).to_be_bytes())?;
+                w.write_all(bytes)?;
+            }
+            ClarityValue::StringUtf8(string) => {
+                let bytes = string.as_bytes();
+                if bytes.len() as u32 > CLARITY_MAX_VALUE_SIZE {
+                    return Err(CodecError::ValueTooLarge {
+                        len: bytes.len(),
+                        max: CLARITY_MAX_VALUE_SIZE as usize,
+                    });
+                }
+
+                w.write_all(&[CLARITY_TYPE_ID_STRING_UTF8])?;
+                w.write_all(&(bytes.len() as u32).to_be_bytes())?;
+                w.write_all(bytes)?;
+            }
+            ClarityValue::Principal(principal) => principal.consensus_serialize(w)?,
+            ClarityValue::Tuple(entries) => {
+                w.write_all(&[CLARITY_TYPE_ID_TUPLE])?;
+                w.write_all(&(entries.len() as u32).to_be_bytes())?;
+
+                for (name, value) in entries {
+                    name.consensus_serialize(w)?;
+                    value.consensus_serialize(w)?;
+                }
+            }
+            ClarityValue::List(values) => {
+                w.write_all(&[CLARITY_TYPE_ID_LIST])?;
+                w.write_all(&(values.len() as u32).to_be_bytes())?;
+
+                for value in values {
+                    value.consensus_serialize(w)?;
+                }
+            }
+            ClarityValue::Optional(None) => w.write_all(&[CLARITY_TYPE_ID_OPTIONAL_NONE])?,
+            ClarityValue::Optional(Some(value)) => {
+                w.write_all(&[CLARITY_TYPE_ID_OPTIONAL_SOME])?;
+                value.consensus_serialize(w)?;
+            }
+            ClarityValue::Response { committed, data } => {
+                w.write_all(&[if *committed {
+                    CLARITY_TYPE_ID_RESPONSE_OK
+                } else {
+                    CLARITY_TYPE_ID_RESPONSE_ERR
+                }])?;
+                data.consensus_serialize(w)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn consensus_deserialize<R: Read>(r: &mut R) -> Result<Self, CodecError> {
+        let mut type_id_buf = [0u8; 1];
+        r.read_exact(&mut type_id_buf)?;
+
+        match type_id_buf[0] {
+            CLARITY_TYPE_ID_INT => {
+                let mut buf = [0u8; 16];
+                r.read_exact(&mut buf)?;
+                Ok(ClarityValue::Int(i128::from_be_bytes(buf)))
+            }
+            CLARITY_TYPE_ID_UINT => {
+                let mut buf = [0u8; 16];
+                r.read_exact(&mut buf)?;
+                Ok(ClarityValue::UInt(u128::from_be_bytes(buf)))
+            }
+            CLARITY_TYPE_ID_BOOL_TRUE => Ok(ClarityValue::Bool(true)),
+            CLARITY_TYPE_ID_BOOL_FALSE => Ok(ClarityValue::Bool(false)),
+            CLARITY_TYPE_ID_BUFFER => {
+                let bytes = read_length_prefixed_bytes(r)?;
+                Ok(ClarityValue::Buffer(bytes))
+            }
+            CLARITY_TYPE_ID_STRING_ASCII => {
+                let bytes = read_length_prefixed_bytes(r)?;
+                let string =
+                    String::from_utf8(bytes).map_err(|_| CodecError::InvalidAsciiString)?;
+
+                if !string.is_ascii() {
+                    return Err(CodecError::InvalidAsciiString);
+                }
+
+                Ok(ClarityValue::StringAscii(string))
+            }
+            CLARITY_TYPE_ID_STRING_UTF8 => {
+                let bytes = read_length_prefixed_bytes(r)?;
+                let string =
+                    String::from_utf8(bytes).map_err(|_| CodecError::InvalidUtf8String)?;
+
+                Ok(ClarityValue::StringUtf8(string))
+            }
+            PRINCIPAL_TYPE_ID_STANDARD | PRINCIPAL_TYPE_ID_CONTRACT
+            | PRINCIPAL_TYPE_ID_VERSIONED_CONTRACT => Ok(ClarityValue::Principal(
+                PrincipalData::consensus_deserialize_with_type_id(type_id_buf[0], r)?,
+            )),
+            CLARITY_TYPE_ID_TUPLE => {
+                let mut count_buf = [0u8; 4];
+                r.read_exact(&mut count_buf)?;
+                let count = u32::from_be_bytes(count_buf);
+
+                let mut entries = BTreeMap::new();
+                for _ in 0..count {
+                    let name = ClarityName::consensus_deserialize(r)?;
+                    let value = ClarityValue::consensus_deserialize(r)?;
+                    entries.insert(name, value);
+                }
+
+                Ok(ClarityValue::Tuple(entries))
+            }
+            CLARITY_TYPE_ID_LIST => {
+                let mut count_buf = [0u8; 4];
+                r.read_exact(&mut count_buf)?;
+                let count = u32::from_be_bytes(count_buf);
+
+                if count > CLARITY_MAX_VALUE_SIZE {
+                    return Err(CodecError::ValueTooLarge {
+                        len: count as usize,
+                        max: CLARITY_MAX_VALUE_SIZE as usize,
+                    });
+                }
+
+                // Built incrementally rather than via `Vec::with_capacity(count as usize)`:
+                // `count` is attacker-controlled and must not drive an eager allocation.
+                let mut values = Vec::new();
+                for _ in 0..count {
+                    values.push(ClarityValue::consensus_deserialize(r)?);
+                }
+
+                Ok(ClarityValue::List(values))
+            }
+            CLARITY_TYPE_ID_OPTIONAL_NONE => Ok(ClarityValue::Optional(None)),
+            CLARITY_TYPE_ID_OPTIONAL_SOME => Ok(ClarityValue::Optional(Some(Box::new(
+                ClarityValue::consensus_deserialize(r)?,
+            )))),
+            CLARITY_TYPE_ID_RESPONSE_OK => Ok(ClarityValue::Response {
+                committed: true,
+                data: Box::new(ClarityValue::consensus_deserialize(r)?),
+            }),
+            CLARITY_TYPE_ID_RESPONSE_ERR => Ok(ClarityValue::Response {
+                committed: false,
+                data: Box::new(ClarityValue::consensus_deserialize(r)?),
+            }),
+            other => Err(CodecError::InvalidClarityTypeId(other)),
+        }
+    }
+}
+
+/// Read a 4-byte big-endian length prefix followed by that many bytes
+fn read_length_prefixed_bytes<R: Read>(r: &mut R) -> Result<Vec<u8>, CodecError> {
+    let mut len_buf = [0u8; 4];
+    r.read_exact(&mut len_buf)?;
+    let len = u32::from_be_bytes(len_buf);
+
+    if len > CLARITY_MAX_VALUE_SIZE {
+        return Err(CodecError::ValueTooLarge {
+            len: len as usize,
+            max: CLARITY_MAX_VALUE_SIZE as usize,
+        });
+    }
+
+    let mut bytes = vec![0u8; len as usize];
+    r.read_exact(&mut bytes)?;
+
+    Ok(bytes)
+}
+
+impl Serialize for ContractName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.0)
+        } else {
+            let mut bytes = Vec::new();
+            self.consensus_serialize(&mut bytes)
+                .map_err(serde::ser::Error::custom)?;
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ContractName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let name = String::deserialize(deserializer)?;
+            ContractName::new(&name).map_err(serde::de::Error::custom)
+        } else {
+            let bytes = ByteBuf::deserialize(deserializer)?.into_vec();
+            ContractName::consensus_deserialize(&mut Cursor::new(bytes))
+                .map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+impl Serialize for StandardPrincipalData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            let mut bytes = Vec::new();
+            self.consensus_serialize(&mut bytes)
+                .map_err(serde::ser::Error::custom)?;
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for StandardPrincipalData {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let address = String::deserialize(deserializer)?;
+            address.parse().map_err(serde::de::Error::custom)
+        } else {
+            let bytes = ByteBuf::deserialize(deserializer)?.into_vec();
+            StandardPrincipalData::consensus_deserialize(&mut Cursor::new(bytes))
+                .map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+impl Serialize for PrincipalData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            let mut bytes = Vec::new();
+            self.consensus_serialize(&mut bytes)
+                .map_err(serde::ser::Error::custom)?;
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PrincipalData {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let principal = String::deserialize(deserializer)?;
+            principal.parse().map_err(serde::de::Error::custom)
+        } else {
+            let bytes = ByteBuf::deserialize(deserializer)?.into_vec();
+            PrincipalData::consensus_deserialize(&mut Cursor::new(bytes))
+                .map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_tokens, Configure, Token};
+
+    use super::*;
+
+    fn test_address() -> StacksAddress {
+        StacksAddress::from([0x11; 20])
+    }
+
+    fn test_version() -> AddressVersion {
+        AddressVersion::try_from(22).expect("22 is a valid mainnet single-sig version byte")
+    }
+
+    #[test]
+    fn contract_name_round_trips_through_consensus_codec() {
+        let name = ContractName::new("my-contract").unwrap();
+
+        let mut bytes = Vec::new();
+        name.consensus_serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), name.serialized_len());
+
+        let decoded = ContractName::consensus_deserialize(&mut Cursor::new(bytes)).unwrap();
+        assert_eq!(decoded, name);
+    }
+
+    #[test]
+    fn contract_name_deserialize_rejects_invalid_format() {
+        // length-prefixed "1", which is not a valid contract name (starts with a digit)
+        let bytes = vec![1u8, b'1'];
+        let err = ContractName::consensus_deserialize(&mut Cursor::new(bytes));
+        assert!(matches!(err, Err(CodecError::InvalidContractName(_))));
+    }
+
+    #[test]
+    fn standard_principal_round_trips_through_consensus_codec() {
+        let principal = StandardPrincipalData::new(test_version(), test_address());
+
+        let mut bytes = Vec::new();
+        principal.consensus_serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), principal.serialized_len());
+
+        let decoded = StandardPrincipalData::consensus_deserialize(&mut Cursor::new(bytes)).unwrap();
+        assert_eq!(decoded, principal);
+    }
+
+    #[test]
+    fn principal_data_round_trips_standard_and_contract() {
+        for principal in [
+            PrincipalData::Standard(StandardPrincipalData::new(test_version(), test_address())),
+            PrincipalData::Contract(
+                StandardPrincipalData::new(test_version(), test_address()),
+                ContractName::new("my-contract").unwrap(),
+            ),
+        ] {
+            let mut bytes = Vec::new();
+            principal.consensus_serialize(&mut bytes).unwrap();
+
+            let decoded = PrincipalData::consensus_deserialize(&mut Cursor::new(bytes)).unwrap();
+            assert_eq!(decoded, principal);
+        }
+    }
+
+    #[test]
+    fn principal_data_deserialize_rejects_unknown_type_id() {
+        let err = PrincipalData::consensus_deserialize(&mut Cursor::new(vec![0xee]));
+        assert!(matches!(err, Err(CodecError::InvalidTypeId(0xee))));
+    }
+
+    #[test]
+    fn principal_data_deserialize_rejects_truncated_input() {
+        let err =
+            PrincipalData::consensus_deserialize(&mut Cursor::new(vec![PRINCIPAL_TYPE_ID_STANDARD]));
+        assert!(matches!(err, Err(CodecError::IoError(_))));
+    }
+
+    #[test]
+    fn standard_principal_display_from_str_round_trips() {
+        let principal = StandardPrincipalData::new(test_version(), test_address());
+
+        let parsed: StandardPrincipalData = principal.to_string().parse().unwrap();
+        assert_eq!(parsed, principal);
+    }
+
+    #[test]
+    fn principal_data_display_from_str_round_trips_contract() {
+        let principal = PrincipalData::Contract(
+            StandardPrincipalData::new(test_version(), test_address()),
+            ContractName::new("my-contract").unwrap(),
+        );
+
+        let parsed: PrincipalData = principal.to_string().parse().unwrap();
+        assert_eq!(parsed, principal);
+    }
+
+    #[test]
+    fn standard_principal_from_str_rejects_bad_checksum() {
+        let err = "SP000000000000000000000000000".parse::<StandardPrincipalData>();
+        assert!(matches!(err, Err(PrincipalDataError::InvalidChecksum)));
+    }
+
+    #[test]
+    fn principal_data_from_str_rejects_invalid_contract_name() {
+        let principal = StandardPrincipalData::new(test_version(), test_address());
+        let err = format!("{principal}.1bad-name").parse::<PrincipalData>();
+        assert!(matches!(err, Err(PrincipalDataError::InvalidContractName(_))));
+    }
+
+    fn clarity_value_round_trip(value: &ClarityValue) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        value.consensus_serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), value.serialized_len());
+
+        let decoded = ClarityValue::consensus_deserialize(&mut Cursor::new(bytes.clone())).unwrap();
+        assert_eq!(&decoded, value);
+
+        bytes
+    }
+
+    #[test]
+    fn clarity_value_round_trips_scalars_and_collections() {
+        let values = vec![
+            ClarityValue::Int(-42),
+            ClarityValue::UInt(42),
+            ClarityValue::Bool(true),
+            ClarityValue::Bool(false),
+            ClarityValue::Buffer(vec![1, 2, 3]),
+            ClarityValue::StringAscii("hello".to_string()),
+            ClarityValue::StringUtf8("héllo".to_string()),
+            ClarityValue::Optional(None),
+            ClarityValue::Optional(Some(Box::new(ClarityValue::Int(7)))),
+            ClarityValue::Response {
+                committed: true,
+                data: Box::new(ClarityValue::UInt(1)),
+            },
+            ClarityValue::Response {
+                committed: false,
+                data: Box::new(ClarityValue::UInt(0)),
+            },
+            ClarityValue::List(vec![ClarityValue::Int(1), ClarityValue::Int(2)]),
+            ClarityValue::Tuple(BTreeMap::from([(
+                ClarityName::new("a").unwrap(),
+                ClarityValue::Int(1),
+            )])),
+        ];
+
+        for value in &values {
+            clarity_value_round_trip(value);
+        }
+    }
+
+    #[test]
+    fn clarity_value_deserialize_rejects_unknown_type_id() {
+        let err = ClarityValue::consensus_deserialize(&mut Cursor::new(vec![0xaa]));
+        assert!(matches!(err, Err(CodecError::InvalidClarityTypeId(0xaa))));
+    }
+
+    #[test]
+    fn clarity_value_buffer_rejects_oversized_length_prefix() {
+        let mut bytes = vec![CLARITY_TYPE_ID_BUFFER];
+        bytes.extend_from_slice(&(CLARITY_MAX_VALUE_SIZE + 1).to_be_bytes());
+
+        let err = ClarityValue::consensus_deserialize(&mut Cursor::new(bytes));
+        assert!(matches!(err, Err(CodecError::ValueTooLarge { .. })));
+    }
+
+    #[test]
+    fn clarity_value_string_ascii_rejects_invalid_utf8() {
+        let mut bytes = vec![CLARITY_TYPE_ID_STRING_ASCII];
+        bytes.extend_from_slice(&1u32.to_be_bytes());
+        bytes.push(0xff);
+
+        let err = ClarityValue::consensus_deserialize(&mut Cursor::new(bytes));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn clarity_value_buffer_rejects_truncated_input() {
+        let mut bytes = vec![CLARITY_TYPE_ID_BUFFER];
+        bytes.extend_from_slice(&10u32.to_be_bytes());
+        bytes.extend_from_slice(&[1, 2, 3]);
+
+        let err = ClarityValue::consensus_deserialize(&mut Cursor::new(bytes));
+        assert!(matches!(err, Err(CodecError::IoError(_))));
+    }
+
+    #[test]
+    fn clarity_value_list_rejects_oversized_element_count_without_allocating() {
+        // Regression test: `count` is attacker-controlled and must be bounded before
+        // it is used to size any allocation.
+        let mut bytes = vec![CLARITY_TYPE_ID_LIST];
+        bytes.extend_from_slice(&(CLARITY_MAX_VALUE_SIZE + 1).to_be_bytes());
+
+        let err = ClarityValue::consensus_deserialize(&mut Cursor::new(bytes));
+        assert!(matches!(err, Err(CodecError::ValueTooLarge { .. })));
+    }
+
+    #[test]
+    fn clarity_name_round_trips_through_consensus_codec() {
+        let name = ClarityName::new("my-var?1").unwrap();
+
+        let mut bytes = Vec::new();
+        name.consensus_serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), name.serialized_len());
+
+        let decoded = ClarityName::consensus_deserialize(&mut Cursor::new(bytes)).unwrap();
+        assert_eq!(decoded, name);
+    }
+
+    #[test]
+    fn clarity_name_rejects_names_that_are_too_long() {
+        let too_long = "a".repeat(CLARITY_MAX_NAME_LENGTH + 1);
+        assert!(matches!(
+            ClarityName::new(&too_long),
+            Err(ClarityNameError::InvalidLength)
+        ));
+    }
+
+    #[test]
+    fn clarity_name_rejects_leading_digit() {
+        assert!(matches!(
+            ClarityName::new("1bad"),
+            Err(ClarityNameError::InvalidFormat)
+        ));
+    }
+
+    #[test]
+    fn clarity_name_deserialize_rejects_invalid_utf8() {
+        let bytes = vec![1u8, 0xff];
+        let err = ClarityName::consensus_deserialize(&mut Cursor::new(bytes));
+        assert!(matches!(err, Err(CodecError::InvalidClarityName(_))));
+    }
+
+    fn test_versioned_contract() -> PrincipalData {
+        PrincipalData::VersionedContract(
+            StandardPrincipalData::new(test_version(), test_address()),
+            ContractName::new("my-contract").unwrap(),
+            ClarityVersion::Clarity2,
+        )
+    }
+
+    #[test]
+    fn versioned_contract_round_trips_through_principal_codec() {
+        let principal = test_versioned_contract();
+
+        let mut bytes = Vec::new();
+        principal.consensus_serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), principal.serialized_len());
+
+        let decoded = PrincipalData::consensus_deserialize(&mut Cursor::new(bytes)).unwrap();
+        assert_eq!(decoded, principal);
+    }
+
+    #[test]
+    fn versioned_contract_round_trips_through_clarity_value() {
+        let value = ClarityValue::Principal(test_versioned_contract());
+        clarity_value_round_trip(&value);
+    }
+
+    #[test]
+    fn versioned_contract_display_from_str_round_trips_with_version() {
+        let principal = test_versioned_contract();
+
+        let rendered = principal.to_string();
+        assert!(rendered.ends_with("+v2"));
+
+        let parsed: PrincipalData = rendered.parse().unwrap();
+        assert_eq!(parsed, principal);
+    }
+
+    #[test]
+    fn principal_data_from_str_rejects_bad_version_suffix() {
+        let principal = StandardPrincipalData::new(test_version(), test_address());
+        let err = format!("{principal}.my-contract+vX").parse::<PrincipalData>();
+        assert!(matches!(err, Err(PrincipalDataError::InvalidClarityVersion(_))));
+    }
+
+    #[test]
+    fn principal_data_deserialize_rejects_missing_version_marker() {
+        let bytes = vec![PRINCIPAL_TYPE_ID_VERSIONED_CONTRACT, 0x00];
+        let err = PrincipalData::consensus_deserialize(&mut Cursor::new(bytes));
+        assert!(matches!(
+            err,
+            Err(CodecError::InvalidClarityVersionMarker(0x00))
+        ));
+    }
+
+    #[test]
+    fn principal_data_deserialize_rejects_unknown_clarity_version() {
+        let bytes = vec![PRINCIPAL_TYPE_ID_VERSIONED_CONTRACT, CLARITY_VERSION_PRESENT, 0xff];
+        let err = PrincipalData::consensus_deserialize(&mut Cursor::new(bytes));
+        assert!(matches!(err, Err(CodecError::InvalidClarityVersion(0xff))));
+    }
+
+    #[test]
+    fn serialized_len_matches_actual_consensus_encoding_length() {
+        let contract_name = ContractName::new("my-contract").unwrap();
+        let mut bytes = Vec::new();
+        contract_name.consensus_serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), contract_name.serialized_len());
+
+        for principal in [
+            PrincipalData::Standard(StandardPrincipalData::new(test_version(), test_address())),
+            PrincipalData::Contract(
+                StandardPrincipalData::new(test_version(), test_address()),
+                contract_name.clone(),
+            ),
+            test_versioned_contract(),
+        ] {
+            let mut bytes = Vec::new();
+            principal.consensus_serialize(&mut bytes).unwrap();
+            assert_eq!(bytes.len(), principal.serialized_len());
+        }
+
+        let value = ClarityValue::Tuple(BTreeMap::from([
+            (ClarityName::new("a").unwrap(), ClarityValue::Int(1)),
+            (
+                ClarityName::new("b").unwrap(),
+                ClarityValue::List(vec![ClarityValue::UInt(1), ClarityValue::UInt(2)]),
+            ),
+        ]));
+        let mut bytes = Vec::new();
+        value.consensus_serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), value.serialized_len());
+    }
+
+    #[test]
+    fn contract_name_serde_round_trips_both_representations() {
+        let name = ContractName::new("my-contract").unwrap();
+        assert_tokens(&name.clone().readable(), &[Token::Str("my-contract")]);
+
+        let mut binary = Vec::new();
+        name.consensus_serialize(&mut binary).unwrap();
+        let binary: &'static [u8] = Box::leak(binary.into_boxed_slice());
+        assert_tokens(&name.compact(), &[Token::Bytes(binary)]);
+    }
+
+    #[test]
+    fn standard_principal_serde_round_trips_both_representations() {
+        let principal = StandardPrincipalData::new(test_version(), test_address());
+
+        let rendered: &'static str = Box::leak(principal.to_string().into_boxed_str());
+        assert_tokens(&principal.clone().readable(), &[Token::Str(rendered)]);
+
+        let mut binary = Vec::new();
+        principal.consensus_serialize(&mut binary).unwrap();
+        let binary: &'static [u8] = Box::leak(binary.into_boxed_slice());
+        assert_tokens(&principal.compact(), &[Token::Bytes(binary)]);
+    }
+
+    #[test]
+    fn principal_data_serde_round_trips_both_representations() {
+        let principal = PrincipalData::Contract(
+            StandardPrincipalData::new(test_version(), test_address()),
+            ContractName::new("my-contract").unwrap(),
+        );
+
+        let rendered: &'static str = Box::leak(principal.to_string().into_boxed_str());
+        assert_tokens(&principal.clone().readable(), &[Token::Str(rendered)]);
+
+        let mut binary = Vec::new();
+        principal.consensus_serialize(&mut binary).unwrap();
+        let binary: &'static [u8] = Box::leak(binary.into_boxed_slice());
+        assert_tokens(&principal.compact(), &[Token::Bytes(binary)]);
+    }
+
+    #[test]
+    fn principal_data_serde_rejects_invalid_human_readable_string() {
+        let result: Result<PrincipalData, _> = serde_json::from_str("\"not.a.valid.principal\"");
+        assert!(result.is_err());
+    }
 }